@@ -0,0 +1,161 @@
+//! Command-line parsing. Pulled out of `main` so argument handling doesn't
+//! grow back into `args[1]` indexing as flags accumulate.
+
+use crate::injector::InjectorOptions;
+
+#[derive(Debug, PartialEq)]
+pub enum Mode {
+    /// Type the given literal/DSL text.
+    Text(String),
+    /// Read literal/DSL text from stdin instead of argv.
+    Stdin,
+    /// Run the live remapping hook against a layer config, optionally
+    /// starting on a named layer instead of the config's first one.
+    Hook { config: String, layer: Option<String> },
+    /// Load a layer config and print a summary without injecting anything.
+    ShowConfig(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub mode: Mode,
+    pub injector: InjectorOptions,
+    pub dry_run: bool,
+}
+
+const USAGE: &str = "Usage: nxlayer [--stdin] [--delay-ms N] [--char-delay-ms N] [--dry-run] <text>\n   or: nxlayer --hook <config.toml> [--layer <name>]\n   or: nxlayer --config <config.toml>";
+
+pub fn parse(args: &[String]) -> Result<Options, String> {
+    let mut injector = InjectorOptions::default();
+    let mut dry_run = false;
+    let mut mode: Option<Mode> = None;
+    let mut text: Option<String> = None;
+    let mut layer: Option<String> = None;
+
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--stdin" => mode = Some(Mode::Stdin),
+            "--hook" => {
+                let path = iter.next().ok_or("--hook requires a config path")?;
+                mode = Some(Mode::Hook {
+                    config: path.clone(),
+                    layer: None,
+                });
+            }
+            "--layer" => {
+                layer = Some(iter.next().ok_or("--layer requires a name")?.clone());
+            }
+            "--config" => {
+                let path = iter.next().ok_or("--config requires a config path")?;
+                mode = Some(Mode::ShowConfig(path.clone()));
+            }
+            "--delay-ms" => {
+                let value = iter.next().ok_or("--delay-ms requires a value")?;
+                injector.settle_delay_ms = value
+                    .parse()
+                    .map_err(|_| format!("invalid --delay-ms value: {value}"))?;
+            }
+            "--char-delay-ms" => {
+                let value = iter.next().ok_or("--char-delay-ms requires a value")?;
+                injector.char_delay_ms = value
+                    .parse()
+                    .map_err(|_| format!("invalid --char-delay-ms value: {value}"))?;
+            }
+            "--dry-run" => dry_run = true,
+            other if text.is_none() => text = Some(other.to_string()),
+            other => return Err(format!("unexpected argument: {other}")),
+        }
+    }
+
+    let mode = match mode {
+        Some(Mode::Hook { config, .. }) => Mode::Hook { config, layer },
+        Some(mode) => {
+            if layer.is_some() {
+                return Err("--layer requires --hook".to_string());
+            }
+            mode
+        }
+        None => {
+            if layer.is_some() {
+                return Err("--layer requires --hook".to_string());
+            }
+            Mode::Text(text.ok_or(USAGE)?)
+        }
+    };
+
+    if dry_run && matches!(mode, Mode::Hook { .. }) {
+        return Err(
+            "--dry-run is not supported with --hook: the hook installs a live system-wide keyboard hook, so there is nothing to preview without calling the injection API".to_string(),
+        );
+    }
+
+    Ok(Options {
+        mode,
+        injector,
+        dry_run,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(parts: &[&str]) -> Vec<String> {
+        std::iter::once("keyboard-inject".to_string())
+            .chain(parts.iter().map(|s| s.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn plain_text_is_positional() {
+        let opts = parse(&args(&["hello"])).unwrap();
+        assert_eq!(opts.mode, Mode::Text("hello".to_string()));
+        assert!(!opts.dry_run);
+    }
+
+    #[test]
+    fn stdin_flag_selects_stdin_mode() {
+        let opts = parse(&args(&["--stdin"])).unwrap();
+        assert_eq!(opts.mode, Mode::Stdin);
+    }
+
+    #[test]
+    fn delay_flags_override_defaults() {
+        let opts = parse(&args(&["--delay-ms", "5", "--char-delay-ms", "7", "hi"])).unwrap();
+        assert_eq!(opts.injector.settle_delay_ms, 5);
+        assert_eq!(opts.injector.char_delay_ms, 7);
+    }
+
+    #[test]
+    fn invalid_delay_value_is_rejected() {
+        assert!(parse(&args(&["--delay-ms", "nope", "hi"])).is_err());
+    }
+
+    #[test]
+    fn hook_with_layer_name() {
+        let opts = parse(&args(&["--hook", "cfg.toml", "--layer", "nav"])).unwrap();
+        assert_eq!(
+            opts.mode,
+            Mode::Hook {
+                config: "cfg.toml".to_string(),
+                layer: Some("nav".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn layer_without_hook_is_rejected() {
+        assert!(parse(&args(&["--layer", "nav", "hi"])).is_err());
+    }
+
+    #[test]
+    fn dry_run_with_hook_is_rejected() {
+        assert!(parse(&args(&["--hook", "cfg.toml", "--dry-run"])).is_err());
+    }
+
+    #[test]
+    fn missing_text_is_an_error() {
+        assert!(parse(&args(&[])).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+//! Config-driven layer system: loads a TOML file describing named layers
+//! that remap an input key (or chord) to an output [`Action`], plus a
+//! modifier key that temporarily activates a layer while held.
+//!
+//! The injection primitives in `main` are reused unchanged as the output
+//! stage; this module is only responsible for loading the config and
+//! resolving a trigger to the action it should produce.
+
+use crate::dsl::{self, Action};
+use crate::key::Key;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs;
+use std::path::Path;
+
+/// A single key or chord used as a layer binding's input side.
+///
+/// Deserialized from a plain string such as `"h"` or `"Ctrl+L"` (the same
+/// modifier grammar as the DSL tokens, but without the surrounding `<>`
+/// since this is a TOML map key / value, not inline text).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct KeyTrigger {
+    mods: Vec<Key>,
+    key: Key,
+}
+
+impl TryFrom<String> for KeyTrigger {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match dsl::parse_chord(&value) {
+            Some(Action::Chord { mods, key }) => Ok(KeyTrigger { mods, key }),
+            _ => Err(format!("invalid key trigger: {value:?}")),
+        }
+    }
+}
+
+impl KeyTrigger {
+    /// Builds a bare, modifier-less trigger for a single key, as seen from
+    /// a live interception backend (e.g. a low-level keyboard hook).
+    ///
+    /// Only the Windows hook backend constructs triggers this way, so this
+    /// is dead code on targets without a hook implementation.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub(crate) fn from_key(key: Key) -> KeyTrigger {
+        KeyTrigger {
+            mods: Vec::new(),
+            key,
+        }
+    }
+
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub(crate) fn key(&self) -> Key {
+        self.key
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Layer {
+    pub name: String,
+    #[serde(default)]
+    pub bindings: HashMap<KeyTrigger, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub layers: Vec<Layer>,
+    /// Only consulted by the Windows hook backend, which holds this key to
+    /// temporarily activate a layer; dead on targets without a hook.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub layer_switch_key: KeyTrigger,
+}
+
+impl Config {
+    pub fn load(path: impl AsRef<Path>) -> Result<Config, String> {
+        let text = fs::read_to_string(path.as_ref())
+            .map_err(|e| format!("reading {}: {e}", path.as_ref().display()))?;
+        toml::from_str(&text).map_err(|e| format!("parsing config: {e}"))
+    }
+
+    /// Finds the layer with the given name, if any.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub fn layer(&self, name: &str) -> Option<&Layer> {
+        self.layers.iter().find(|l| l.name == name)
+    }
+}
+
+impl Layer {
+    /// Resolves a trigger to the action list it produces on this layer, if
+    /// bound. The binding's value is parsed with the same DSL used for
+    /// command-line text, so it may be literal text, a named key, or a
+    /// chord.
+    #[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+    pub fn resolve(&self, trigger: &KeyTrigger) -> Option<Vec<Action>> {
+        self.bindings.get(trigger).map(|value| dsl::parse(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    const NAV_CONFIG: &str = r#"
+        layer_switch_key = "Tab"
+
+        [[layers]]
+        name = "nav"
+        [layers.bindings]
+        h = "<Left>"
+        "Ctrl+L" = "logged in"
+
+        [[layers]]
+        name = "symbols"
+    "#;
+
+    #[test]
+    fn key_trigger_parses_plain_and_chord_strings() {
+        let plain: KeyTrigger = "h".to_string().try_into().unwrap();
+        assert_eq!(plain, KeyTrigger::from_key(Key::Char('h')));
+
+        let chord: KeyTrigger = "Ctrl+L".to_string().try_into().unwrap();
+        assert_eq!(chord.mods, vec![Key::Ctrl]);
+        assert_eq!(chord.key, Key::Char('l'));
+    }
+
+    #[test]
+    fn key_trigger_rejects_garbage() {
+        let result: Result<KeyTrigger, _> = "not a key".to_string().try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn config_loads_multiple_layers_and_finds_by_name() {
+        let cfg: Config = toml::from_str(NAV_CONFIG).unwrap();
+        assert_eq!(cfg.layers.len(), 2);
+        assert_eq!(cfg.layer_switch_key.key(), Key::Tab);
+        assert!(cfg.layer("nav").is_some());
+        assert!(cfg.layer("symbols").is_some());
+        assert!(cfg.layer("missing").is_none());
+    }
+
+    #[test]
+    fn layer_resolve_looks_up_bindings_by_trigger() {
+        let cfg: Config = toml::from_str(NAV_CONFIG).unwrap();
+        let nav = cfg.layer("nav").unwrap();
+
+        let h_trigger = KeyTrigger::from_key(Key::Char('h'));
+        assert_eq!(nav.resolve(&h_trigger), Some(dsl::parse("<Left>")));
+
+        let unbound_trigger = KeyTrigger::from_key(Key::Char('z'));
+        assert_eq!(nav.resolve(&unbound_trigger), None);
+    }
+}
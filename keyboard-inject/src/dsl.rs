@@ -0,0 +1,151 @@
+//! A small keystroke DSL so callers can express modifier chords and named
+//! keys instead of only literal text.
+//!
+//! Tokens are written as `<...>` and may combine modifiers with `+`, e.g.
+//! `<Ctrl+L>`, `<Ctrl+Shift+Esc>`, `<Win+R>`. A token with no modifiers
+//! names a single key, e.g. `<Tab>`, `<Enter>`, `<Up>`, `<F5>`. Anything
+//! between tokens is literal text. Use `<<` to type a literal `<`.
+
+use crate::key::{self, Key};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Text(String),
+    Chord { mods: Vec<Key>, key: Key },
+}
+
+/// Parses a single `<...>` token body (without the angle brackets) into a
+/// chord: all but the last `+`-separated part are modifiers, the last part
+/// is the base key.
+pub(crate) fn parse_chord(body: &str) -> Option<Action> {
+    let parts: Vec<&str> = body.split('+').collect();
+    let (key_name, mod_names) = parts.split_last()?;
+
+    let mut mods = Vec::with_capacity(mod_names.len());
+    for name in mod_names {
+        let key = key::by_name(name)?;
+        if !key.is_modifier() {
+            return None;
+        }
+        mods.push(key);
+    }
+    let key = key::by_name(key_name)?;
+
+    Some(Action::Chord { mods, key })
+}
+
+/// Tokenizes a DSL string into an ordered list of actions, coalescing
+/// adjacent literal text into a single `Action::Text`.
+pub fn parse(input: &str) -> Vec<Action> {
+    let mut actions = Vec::new();
+    let mut text = String::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '<' {
+            if chars.peek() == Some(&'<') {
+                chars.next();
+                text.push('<');
+                continue;
+            }
+
+            let mut body = String::new();
+            let mut closed = false;
+            for c in chars.by_ref() {
+                if c == '>' {
+                    closed = true;
+                    break;
+                }
+                body.push(c);
+            }
+
+            if closed {
+                if let Some(action) = parse_chord(&body) {
+                    if !text.is_empty() {
+                        actions.push(Action::Text(std::mem::take(&mut text)));
+                    }
+                    actions.push(action);
+                    continue;
+                }
+            }
+
+            // Not a recognized token: treat the literal span as text.
+            text.push('<');
+            text.push_str(&body);
+            if closed {
+                text.push('>');
+            }
+        } else {
+            text.push(c);
+        }
+    }
+
+    if !text.is_empty() {
+        actions.push(Action::Text(text));
+    }
+
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_chord_handles_single_key_and_modifiers() {
+        assert_eq!(
+            parse_chord("L"),
+            Some(Action::Chord {
+                mods: Vec::new(),
+                key: Key::Char('l'),
+            })
+        );
+        assert_eq!(
+            parse_chord("Ctrl+Shift+Esc"),
+            Some(Action::Chord {
+                mods: vec![Key::Ctrl, Key::Shift],
+                key: Key::Escape,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_chord_rejects_unknown_or_non_modifier_prefix() {
+        assert_eq!(parse_chord("Ctrl+NotAKey"), None);
+        // "L" isn't a modifier, so it can't appear in the modifier position.
+        assert_eq!(parse_chord("L+Ctrl"), None);
+    }
+
+    #[test]
+    fn parse_splits_text_and_tokens() {
+        assert_eq!(
+            parse("hi <Ctrl+L> there"),
+            vec![
+                Action::Text("hi ".to_string()),
+                Action::Chord {
+                    mods: vec![Key::Ctrl],
+                    key: Key::Char('l'),
+                },
+                Action::Text(" there".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_coalesces_adjacent_text_around_unknown_tokens() {
+        assert_eq!(
+            parse("a<NotAKey>b"),
+            vec![Action::Text("a<NotAKey>b".to_string())]
+        );
+    }
+
+    #[test]
+    fn parse_handles_literal_angle_bracket_escape() {
+        assert_eq!(parse("a<<b"), vec![Action::Text("a<b".to_string())]);
+    }
+
+    #[test]
+    fn parse_plain_text_with_no_tokens() {
+        assert_eq!(parse("hello"), vec![Action::Text("hello".to_string())]);
+    }
+}
@@ -0,0 +1,149 @@
+//! Daemon mode (`nxlayer --hook <config.toml>`): installs a `WH_KEYBOARD_LL`
+//! hook and remaps keystrokes live against whatever application has focus,
+//! instead of only blasting text into the foreground window once.
+//!
+//! Windows-only: `WH_KEYBOARD_LL` has no equivalent in the Linux injector
+//! backend, which only supports one-shot synthesis for now.
+//!
+//! Each observed key is looked up against the active layer's bindings; a
+//! match is swallowed and replaced with the bound action sent through the
+//! same injection path used by one-shot mode. Our own synthetic events are
+//! tagged with a sentinel and skipped in the callback so replacement
+//! keystrokes don't get reprocessed.
+
+use crate::config::{Config, KeyTrigger};
+use crate::injector::windows::{key_from_vkey, WindowsInjector, SYNTHETIC_EVENT_MARKER};
+use crate::injector::InjectorOptions;
+use crate::key::Key;
+use crate::send_actions;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use windows::Win32::Foundation::{LPARAM, LRESULT, WPARAM};
+use windows::Win32::UI::Input::KeyboardAndMouse::KBDLLHOOKSTRUCT;
+use windows::Win32::UI::WindowsAndMessaging::{
+    CallNextHookEx, DispatchMessageW, GetMessageW, SetWindowsHookExW, TranslateMessage,
+    UnhookWindowsHookEx, HHOOK, MSG, WH_KEYBOARD_LL, WM_KEYDOWN, WM_KEYUP, WM_SYSKEYDOWN,
+    WM_SYSKEYUP,
+};
+
+struct HookState {
+    config: Config,
+    /// Name of the layer currently consulted for bindings while the layer
+    /// switch key is held. Fixed for the lifetime of the hook; there is no
+    /// live layer-switching UI yet, just a choice of which one to start on.
+    active_layer: String,
+    layer_held: bool,
+    /// Physical keys whose keydown was swallowed and replaced with a bound
+    /// action. Their matching keyup must be swallowed too, or the focused
+    /// app sees a keyup with no keydown of its own.
+    swallowed_keys: HashSet<Key>,
+    injector: WindowsInjector,
+}
+
+static HOOK_STATE: OnceLock<Mutex<HookState>> = OnceLock::new();
+
+/// Picks which configured layer the hook should use: the named layer if
+/// one was requested, otherwise the config's first layer. Errors if a
+/// named layer doesn't exist, or if the config has no layers at all.
+fn resolve_active_layer(config: &Config, layer: Option<&str>) -> Result<String, String> {
+    match layer {
+        Some(name) => config
+            .layer(name)
+            .map(|l| l.name.clone())
+            .ok_or_else(|| format!("no such layer: {name:?}")),
+        None => config
+            .layers
+            .first()
+            .map(|l| l.name.clone())
+            .ok_or_else(|| "config has no layers".to_string()),
+    }
+}
+
+/// Installs the hook and pumps messages on the calling thread until it is
+/// unhooked (low-level keyboard hooks only fire while their installing
+/// thread runs a message loop).
+pub fn run(config: Config, layer: Option<&str>) -> Result<(), String> {
+    let active_layer = resolve_active_layer(&config, layer)?;
+
+    HOOK_STATE
+        .set(Mutex::new(HookState {
+            config,
+            active_layer,
+            layer_held: false,
+            swallowed_keys: HashSet::new(),
+            injector: WindowsInjector::new(InjectorOptions::default()),
+        }))
+        .map_err(|_| ())
+        .expect("hook::run called more than once");
+
+    unsafe {
+        let hook = SetWindowsHookExW(WH_KEYBOARD_LL, Some(hook_proc), None, 0)
+            .map_err(|e| e.to_string())?;
+
+        let mut msg = MSG::default();
+        while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+            let _ = TranslateMessage(&msg);
+            DispatchMessageW(&msg);
+        }
+
+        UnhookWindowsHookEx(hook).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+unsafe extern "system" fn hook_proc(code: i32, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if code < 0 {
+        return CallNextHookEx(HHOOK(0), code, wparam, lparam);
+    }
+
+    let event = &*(lparam.0 as *const KBDLLHOOKSTRUCT);
+
+    // Our own synthesized keystrokes: let them through untouched so we
+    // never re-intercept and loop on our own output.
+    if event.dwExtraInfo == SYNTHETIC_EVENT_MARKER {
+        return CallNextHookEx(HHOOK(0), code, wparam, lparam);
+    }
+
+    let msg = wparam.0 as u32;
+    let key_down = msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN;
+    let key_up = msg == WM_KEYUP || msg == WM_SYSKEYUP;
+    if !key_down && !key_up {
+        return CallNextHookEx(HHOOK(0), code, wparam, lparam);
+    }
+
+    let Some(key) = key_from_vkey(event.vkCode as u16) else {
+        return CallNextHookEx(HHOOK(0), code, wparam, lparam);
+    };
+    let trigger = KeyTrigger::from_key(key);
+
+    let Some(state_lock) = HOOK_STATE.get() else {
+        return CallNextHookEx(HHOOK(0), code, wparam, lparam);
+    };
+    let mut state = state_lock.lock().unwrap();
+
+    if trigger.key() == state.config.layer_switch_key.key() {
+        state.layer_held = key_down;
+        return LRESULT(1); // hold-to-activate: never forward to the app
+    }
+
+    if key_down && state.layer_held {
+        let actions = state
+            .config
+            .layer(&state.active_layer)
+            .and_then(|layer| layer.resolve(&trigger));
+
+        if let Some(actions) = actions {
+            send_actions(&mut state.injector, &actions);
+            state.swallowed_keys.insert(trigger.key());
+            return LRESULT(1); // swallow the original key
+        }
+    }
+
+    if key_up && state.swallowed_keys.remove(&trigger.key()) {
+        return LRESULT(1); // matching keyup for a swallowed keydown
+    }
+
+    CallNextHookEx(HHOOK(0), code, wparam, lparam)
+}
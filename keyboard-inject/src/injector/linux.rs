@@ -0,0 +1,381 @@
+//! Linux injection backend: opens `/dev/uinput`, registers a virtual
+//! keyboard device, and emits raw `input_event` structs (keydown/keyup +
+//! `SYN_REPORT`) for each key. This lets the same DSL and config engine
+//! that drives the Windows `SendInput` backend run unchanged on Linux.
+
+use super::{InjectorOptions, KeyInjector};
+use crate::key::Key;
+use std::fs::{File, OpenOptions};
+use std::mem;
+use std::os::unix::io::AsRawFd;
+
+const EV_KEY: u16 = 0x01;
+const EV_SYN: u16 = 0x00;
+const SYN_REPORT: u16 = 0;
+
+const UI_DEV_CREATE: libc::c_ulong = 0x5501;
+const UI_DEV_DESTROY: libc::c_ulong = 0x5502;
+const UI_SET_EVBIT: libc::c_ulong = 0x40045564;
+const UI_SET_KEYBIT: libc::c_ulong = 0x40045565;
+
+const UINPUT_MAX_NAME_SIZE: usize = 80;
+
+// A representative subset of linux/input-event-codes.h KEY_* values,
+// enough to type ASCII letters and digits and drive the named keys the DSL
+// understands.
+const KEY_ESC: u16 = 1;
+const KEY_1: u16 = 2;
+const KEY_BACKSPACE: u16 = 14;
+const KEY_TAB: u16 = 15;
+const KEY_ENTER: u16 = 28;
+const KEY_LEFTCTRL: u16 = 29;
+const KEY_A: u16 = 30;
+const KEY_LEFTSHIFT: u16 = 42;
+const KEY_Y: u16 = 21;
+const KEY_Z: u16 = 44;
+const KEY_SPACE: u16 = 57;
+const KEY_LEFTALT: u16 = 56;
+const KEY_F1: u16 = 59;
+const KEY_F11: u16 = 87;
+const KEY_F12: u16 = 88;
+const KEY_HOME: u16 = 102;
+const KEY_UP: u16 = 103;
+const KEY_PAGEUP: u16 = 104;
+const KEY_LEFT: u16 = 105;
+const KEY_RIGHT: u16 = 106;
+const KEY_END: u16 = 107;
+const KEY_DOWN: u16 = 108;
+const KEY_PAGEDOWN: u16 = 109;
+const KEY_DELETE: u16 = 111;
+const KEY_LEFTMETA: u16 = 125;
+
+/// QWERTY row layout used to translate ASCII letters/digits to KEY_* codes,
+/// mirroring the physical key each character sits on.
+const DIGIT_ROW: [u16; 10] = [
+    11, KEY_1, 3, 4, 5, 6, 7, 8, 9, 10, // 0,1..9
+];
+const LETTER_ROW: [u16; 26] = [
+    KEY_A, 48, 46, 32, 18, 33, 34, 35, 23, 36, 37, 38, 50, 49, 24, 25, 16, 19, 31, 20, 22, 47, 17,
+    45, KEY_Y, KEY_Z,
+];
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct TimeVal {
+    tv_sec: libc::c_long,
+    tv_usec: libc::c_long,
+}
+
+#[repr(C)]
+struct InputEvent {
+    time: TimeVal,
+    kind: u16,
+    code: u16,
+    value: i32,
+}
+
+#[repr(C)]
+struct UinputSetup {
+    id: InputId,
+    name: [u8; UINPUT_MAX_NAME_SIZE],
+    ff_effects_max: u32,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct InputId {
+    bustype: u16,
+    vendor: u16,
+    product: u16,
+    version: u16,
+}
+
+fn keycode_of(key: Key) -> Option<u16> {
+    let code = match key {
+        Key::Char(c) if c.is_ascii_digit() => DIGIT_ROW[(c as u8 - b'0') as usize],
+        Key::Char(c) if c.is_ascii_alphabetic() => {
+            LETTER_ROW[(c.to_ascii_lowercase() as u8 - b'a') as usize]
+        }
+        Key::Char(_) => return None,
+        Key::Ctrl => KEY_LEFTCTRL,
+        Key::Shift => KEY_LEFTSHIFT,
+        Key::Alt => KEY_LEFTALT,
+        Key::Win => KEY_LEFTMETA,
+        Key::Tab => KEY_TAB,
+        Key::Enter => KEY_ENTER,
+        Key::Escape => KEY_ESC,
+        Key::Space => KEY_SPACE,
+        Key::Backspace => KEY_BACKSPACE,
+        Key::Delete => KEY_DELETE,
+        Key::Up => KEY_UP,
+        Key::Down => KEY_DOWN,
+        Key::Left => KEY_LEFT,
+        Key::Right => KEY_RIGHT,
+        Key::Home => KEY_HOME,
+        Key::End => KEY_END,
+        Key::PageUp => KEY_PAGEUP,
+        Key::PageDown => KEY_PAGEDOWN,
+        Key::F1 | Key::F2 | Key::F3 | Key::F4 | Key::F5 | Key::F6 | Key::F7 | Key::F8
+        | Key::F9 | Key::F10 => {
+            let offset = match key {
+                Key::F1 => 0,
+                Key::F2 => 1,
+                Key::F3 => 2,
+                Key::F4 => 3,
+                Key::F5 => 4,
+                Key::F6 => 5,
+                Key::F7 => 6,
+                Key::F8 => 7,
+                Key::F9 => 8,
+                Key::F10 => 9,
+                _ => unreachable!(),
+            };
+            KEY_F1 + offset
+        }
+        Key::F11 => KEY_F11,
+        Key::F12 => KEY_F12,
+    };
+    Some(code)
+}
+
+pub struct LinuxInjector {
+    device: File,
+    opts: InjectorOptions,
+}
+
+impl LinuxInjector {
+    pub fn new(opts: InjectorOptions) -> std::io::Result<LinuxInjector> {
+        let device = OpenOptions::new()
+            .write(true)
+            .open("/dev/uinput")?;
+        let fd = device.as_raw_fd();
+
+        unsafe {
+            libc::ioctl(fd, UI_SET_EVBIT, EV_KEY as libc::c_int);
+
+            // Register every keycode we might emit.
+            let mut codes: Vec<u16> = DIGIT_ROW.to_vec();
+            codes.extend_from_slice(&LETTER_ROW);
+            codes.extend_from_slice(&[
+                KEY_ESC,
+                KEY_BACKSPACE,
+                KEY_TAB,
+                KEY_ENTER,
+                KEY_LEFTCTRL,
+                KEY_LEFTSHIFT,
+                KEY_LEFTALT,
+                KEY_LEFTMETA,
+                KEY_SPACE,
+                KEY_HOME,
+                KEY_UP,
+                KEY_PAGEUP,
+                KEY_LEFT,
+                KEY_RIGHT,
+                KEY_END,
+                KEY_DOWN,
+                KEY_PAGEDOWN,
+                KEY_DELETE,
+                KEY_F11,
+                KEY_F12,
+            ]);
+            for f in 0..10u16 {
+                codes.push(KEY_F1 + f);
+            }
+            for code in codes {
+                libc::ioctl(fd, UI_SET_KEYBIT, code as libc::c_int);
+            }
+
+            let mut setup: UinputSetup = mem::zeroed();
+            setup.id.bustype = 0x03; // BUS_USB
+            setup.id.vendor = 0x1234;
+            setup.id.product = 0x5678;
+            let name = b"nxlayer-virtual-keyboard";
+            setup.name[..name.len()].copy_from_slice(name);
+
+            // UI_DEV_SETUP's ioctl number depends on the size of
+            // uinput_setup and isn't a plain constant across libc
+            // versions, so we write the legacy uinput_user_dev-free path
+            // by issuing UI_DEV_SETUP via its fixed magic/number directly.
+            const UI_DEV_SETUP: libc::c_ulong = 0x405c5503;
+            if libc::ioctl(fd, UI_DEV_SETUP, &setup as *const UinputSetup) < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+
+            if libc::ioctl(fd, UI_DEV_CREATE) < 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+        }
+
+        // Give the kernel a moment to finish registering the device before
+        // the first event is emitted.
+        std::thread::sleep(std::time::Duration::from_millis(opts.settle_delay_ms));
+
+        Ok(LinuxInjector { device, opts })
+    }
+
+    fn emit(&self, kind: u16, code: u16, value: i32) {
+        let event = InputEvent {
+            time: TimeVal {
+                tv_sec: 0,
+                tv_usec: 0,
+            },
+            kind,
+            code,
+            value,
+        };
+        unsafe {
+            libc::write(
+                self.device.as_raw_fd(),
+                &event as *const InputEvent as *const libc::c_void,
+                mem::size_of::<InputEvent>(),
+            );
+        }
+    }
+
+    fn sync(&self) {
+        self.emit(EV_SYN, SYN_REPORT, 0);
+    }
+
+    fn press_release(&self, code: u16) {
+        self.emit(EV_KEY, code, 1);
+        self.sync();
+        self.emit(EV_KEY, code, 0);
+        self.sync();
+    }
+}
+
+impl Drop for LinuxInjector {
+    fn drop(&mut self) {
+        unsafe {
+            libc::ioctl(self.device.as_raw_fd(), UI_DEV_DESTROY);
+        }
+    }
+}
+
+impl KeyInjector for LinuxInjector {
+    fn type_text(&mut self, text: &str) {
+        for c in text.chars() {
+            let needs_shift = c.is_ascii_uppercase();
+            let Some(code) = keycode_of(Key::Char(c)) else {
+                eprintln!("Skipping character with no uinput keycode mapping: {c:?}");
+                continue;
+            };
+
+            if needs_shift {
+                self.emit(EV_KEY, KEY_LEFTSHIFT, 1);
+                self.sync();
+            }
+
+            self.press_release(code);
+
+            if needs_shift {
+                self.emit(EV_KEY, KEY_LEFTSHIFT, 0);
+                self.sync();
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(self.opts.char_delay_ms));
+        }
+    }
+
+    fn key_down(&mut self, key: Key) {
+        if let Some(code) = keycode_of(key) {
+            self.emit(EV_KEY, code, 1);
+            self.sync();
+        }
+    }
+
+    fn key_up(&mut self, key: Key) {
+        if let Some(code) = keycode_of(key) {
+            self.emit(EV_KEY, code, 0);
+            self.sync();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // linux/input-event-codes.h KEY_* values for the top three QWERTY rows,
+    // kept independent of LETTER_ROW/DIGIT_ROW so a transposed entry (like
+    // the y/z swap this test was added to catch) fails loudly.
+    const EXPECTED_LETTER_CODES: [u16; 26] = [
+        30, 48, 46, 32, 18, 33, 34, 35, 23, 36, 37, 38, 50, 49, 24, 25, 16, 19, 31, 20, 22, 47, 17,
+        45, 21, 44,
+    ];
+    const EXPECTED_DIGIT_CODES: [u16; 10] = [11, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    #[test]
+    fn keycode_of_matches_known_letter_codes() {
+        for (i, c) in ('a'..='z').enumerate() {
+            assert_eq!(
+                keycode_of(Key::Char(c)),
+                Some(EXPECTED_LETTER_CODES[i]),
+                "wrong keycode for {c:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn keycode_of_matches_known_digit_codes() {
+        for (i, c) in ('0'..='9').enumerate() {
+            assert_eq!(
+                keycode_of(Key::Char(c)),
+                Some(EXPECTED_DIGIT_CODES[i]),
+                "wrong keycode for {c:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn keycode_of_covers_every_named_key_without_collisions() {
+        let named_keys = [
+            Key::Ctrl,
+            Key::Shift,
+            Key::Alt,
+            Key::Win,
+            Key::Tab,
+            Key::Enter,
+            Key::Escape,
+            Key::Space,
+            Key::Backspace,
+            Key::Delete,
+            Key::Up,
+            Key::Down,
+            Key::Left,
+            Key::Right,
+            Key::Home,
+            Key::End,
+            Key::PageUp,
+            Key::PageDown,
+            Key::F1,
+            Key::F2,
+            Key::F3,
+            Key::F4,
+            Key::F5,
+            Key::F6,
+            Key::F7,
+            Key::F8,
+            Key::F9,
+            Key::F10,
+            Key::F11,
+            Key::F12,
+        ];
+        let codes: Vec<u16> = named_keys.iter().map(|&k| keycode_of(k).unwrap()).collect();
+        for i in 0..codes.len() {
+            for j in (i + 1)..codes.len() {
+                assert_ne!(
+                    codes[i], codes[j],
+                    "{:?} and {:?} map to the same keycode",
+                    named_keys[i], named_keys[j]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn keycode_of_rejects_non_alnum_chars() {
+        assert_eq!(keycode_of(Key::Char('!')), None);
+        assert_eq!(keycode_of(Key::Char('é')), None);
+    }
+}
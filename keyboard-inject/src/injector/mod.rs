@@ -0,0 +1,87 @@
+//! Platform injection backends. Everything above this module (the DSL,
+//! config, hook and `main`) talks in terms of [`Key`](crate::key::Key) and
+//! the [`KeyInjector`] trait; only the backend knows how to turn that into
+//! real OS-level keystrokes.
+
+#[cfg(target_os = "windows")]
+pub(crate) mod windows;
+#[cfg(target_os = "windows")]
+pub use windows::WindowsInjector;
+
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "linux")]
+pub use linux::LinuxInjector;
+
+use crate::key::Key;
+
+pub trait KeyInjector {
+    /// Types a run of literal text, choosing whatever representation the
+    /// backend needs (e.g. Unicode code units on Windows, keycodes on
+    /// Linux).
+    fn type_text(&mut self, text: &str);
+
+    /// Presses a single key (modifier or otherwise) without releasing it.
+    fn key_down(&mut self, key: Key);
+
+    /// Releases a single key previously sent to `key_down`.
+    fn key_up(&mut self, key: Key);
+}
+
+/// Timing knobs that used to be hardcoded `sleep` constants in the
+/// injection backends. `settle_delay_ms` is the pause before a backend
+/// starts typing (giving the target window time to receive focus): the
+/// Linux backend waits it once after creating its device, the Windows
+/// backend before each run of text. `char_delay_ms` paces typed text, since
+/// some target applications drop input sent faster than they can process
+/// it: the Linux backend waits it after every character, the Windows
+/// backend once after each run of text, which it submits in a single
+/// `SendInput` call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InjectorOptions {
+    pub settle_delay_ms: u64,
+    pub char_delay_ms: u64,
+}
+
+impl Default for InjectorOptions {
+    fn default() -> Self {
+        InjectorOptions {
+            settle_delay_ms: 50,
+            char_delay_ms: 1,
+        }
+    }
+}
+
+/// Constructs the injector for the current platform.
+pub fn make_injector(opts: InjectorOptions) -> Box<dyn KeyInjector> {
+    #[cfg(target_os = "windows")]
+    {
+        Box::new(WindowsInjector::new(opts))
+    }
+    #[cfg(target_os = "linux")]
+    {
+        Box::new(LinuxInjector::new(opts).expect("failed to open /dev/uinput"))
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        compile_error!("nxlayer has no injection backend for this target_os");
+    }
+}
+
+/// A `KeyInjector` that only prints the resolved event sequence instead of
+/// calling any OS injection API, backing `--dry-run`.
+pub struct DryRunInjector;
+
+impl KeyInjector for DryRunInjector {
+    fn type_text(&mut self, text: &str) {
+        println!("TEXT {text:?}");
+    }
+
+    fn key_down(&mut self, key: Key) {
+        println!("DOWN {key:?}");
+    }
+
+    fn key_up(&mut self, key: Key) {
+        println!("UP   {key:?}");
+    }
+}
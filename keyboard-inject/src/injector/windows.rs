@@ -0,0 +1,185 @@
+use super::{InjectorOptions, KeyInjector};
+use crate::key::Key;
+use windows::Win32::UI::Input::KeyboardAndMouse::*;
+
+/// Marks INPUT records we generate ourselves so the hook mode can
+/// recognize and ignore its own synthetic events instead of reprocessing them.
+pub(crate) const SYNTHETIC_EVENT_MARKER: usize = 0x4E58_4C52; // "NXLR"
+
+fn vkey_of(key: Key) -> VIRTUAL_KEY {
+    match key {
+        Key::Char(c) if c.is_ascii_alphanumeric() => VIRTUAL_KEY(c.to_ascii_uppercase() as u16),
+        Key::Char(c) => VIRTUAL_KEY(c as u16),
+        Key::Ctrl => VK_CONTROL,
+        Key::Shift => VK_SHIFT,
+        Key::Alt => VK_MENU,
+        Key::Win => VK_LWIN,
+        Key::Tab => VK_TAB,
+        Key::Enter => VK_RETURN,
+        Key::Escape => VK_ESCAPE,
+        Key::Space => VK_SPACE,
+        Key::Backspace => VK_BACK,
+        Key::Delete => VK_DELETE,
+        Key::Up => VK_UP,
+        Key::Down => VK_DOWN,
+        Key::Left => VK_LEFT,
+        Key::Right => VK_RIGHT,
+        Key::Home => VK_HOME,
+        Key::End => VK_END,
+        Key::PageUp => VK_PRIOR,
+        Key::PageDown => VK_NEXT,
+        Key::F1 => VK_F1,
+        Key::F2 => VK_F2,
+        Key::F3 => VK_F3,
+        Key::F4 => VK_F4,
+        Key::F5 => VK_F5,
+        Key::F6 => VK_F6,
+        Key::F7 => VK_F7,
+        Key::F8 => VK_F8,
+        Key::F9 => VK_F9,
+        Key::F10 => VK_F10,
+        Key::F11 => VK_F11,
+        Key::F12 => VK_F12,
+    }
+}
+
+/// Inverse of [`vkey_of`], used by the hook mode to turn an observed
+/// `KBDLLHOOKSTRUCT::vkCode` back into a platform-independent `Key` before
+/// it's matched against config bindings.
+pub(crate) fn key_from_vkey(vkey: u16) -> Option<Key> {
+    let key = match VIRTUAL_KEY(vkey) {
+        VK_CONTROL | VK_LCONTROL | VK_RCONTROL => Key::Ctrl,
+        VK_SHIFT | VK_LSHIFT | VK_RSHIFT => Key::Shift,
+        VK_MENU | VK_LMENU | VK_RMENU => Key::Alt,
+        VK_LWIN | VK_RWIN => Key::Win,
+        VK_TAB => Key::Tab,
+        VK_RETURN => Key::Enter,
+        VK_ESCAPE => Key::Escape,
+        VK_SPACE => Key::Space,
+        VK_BACK => Key::Backspace,
+        VK_DELETE => Key::Delete,
+        VK_UP => Key::Up,
+        VK_DOWN => Key::Down,
+        VK_LEFT => Key::Left,
+        VK_RIGHT => Key::Right,
+        VK_HOME => Key::Home,
+        VK_END => Key::End,
+        VK_PRIOR => Key::PageUp,
+        VK_NEXT => Key::PageDown,
+        VK_F1 => Key::F1,
+        VK_F2 => Key::F2,
+        VK_F3 => Key::F3,
+        VK_F4 => Key::F4,
+        VK_F5 => Key::F5,
+        VK_F6 => Key::F6,
+        VK_F7 => Key::F7,
+        VK_F8 => Key::F8,
+        VK_F9 => Key::F9,
+        VK_F10 => Key::F10,
+        VK_F11 => Key::F11,
+        VK_F12 => Key::F12,
+        other if (0x30..=0x39).contains(&other.0) => Key::Char((other.0 as u8) as char),
+        other if (0x41..=0x5A).contains(&other.0) => {
+            Key::Char((other.0 as u8 as char).to_ascii_lowercase())
+        }
+        _ => return None,
+    };
+    Some(key)
+}
+
+pub struct WindowsInjector {
+    opts: InjectorOptions,
+}
+
+impl WindowsInjector {
+    pub fn new(opts: InjectorOptions) -> WindowsInjector {
+        WindowsInjector { opts }
+    }
+
+    fn send_vkey(&self, vkey: VIRTUAL_KEY, key_up: bool) {
+        let mut flags = KEYBD_EVENT_FLAGS(0);
+        if key_up {
+            flags |= KEYEVENTF_KEYUP;
+        }
+
+        let input = INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: vkey,
+                    wScan: 0,
+                    dwFlags: flags,
+                    time: 0,
+                    dwExtraInfo: SYNTHETIC_EVENT_MARKER,
+                },
+            },
+        };
+
+        unsafe {
+            SendInput(&[input], std::mem::size_of::<INPUT>() as i32);
+        }
+    }
+
+    /// Builds one keydown+keyup `INPUT` pair for a UTF-16 code unit using
+    /// `KEYEVENTF_UNICODE`. `wVk` is left at 0 and the code unit goes in
+    /// `wScan`, which is what makes this layout-independent. Surrogate
+    /// pairs are sent as two code units, each through this same path, so
+    /// astral-plane characters work as long as callers iterate
+    /// `encode_utf16`.
+    fn unicode_input_pair(unit: u16) -> [INPUT; 2] {
+        let down = INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: VIRTUAL_KEY(0),
+                    wScan: unit,
+                    dwFlags: KEYEVENTF_UNICODE,
+                    time: 0,
+                    dwExtraInfo: SYNTHETIC_EVENT_MARKER,
+                },
+            },
+        };
+        let up = INPUT {
+            r#type: INPUT_KEYBOARD,
+            Anonymous: INPUT_0 {
+                ki: KEYBDINPUT {
+                    wVk: VIRTUAL_KEY(0),
+                    wScan: unit,
+                    dwFlags: KEYEVENTF_UNICODE | KEYEVENTF_KEYUP,
+                    time: 0,
+                    dwExtraInfo: SYNTHETIC_EVENT_MARKER,
+                },
+            },
+        };
+        [down, up]
+    }
+}
+
+impl KeyInjector for WindowsInjector {
+    fn type_text(&mut self, text: &str) {
+        std::thread::sleep(std::time::Duration::from_millis(self.opts.settle_delay_ms));
+
+        let inputs: Vec<INPUT> = text
+            .encode_utf16()
+            .flat_map(Self::unicode_input_pair)
+            .collect();
+
+        if inputs.is_empty() {
+            return;
+        }
+
+        unsafe {
+            SendInput(&inputs, std::mem::size_of::<INPUT>() as i32);
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(self.opts.char_delay_ms));
+    }
+
+    fn key_down(&mut self, key: Key) {
+        self.send_vkey(vkey_of(key), false);
+    }
+
+    fn key_up(&mut self, key: Key) {
+        self.send_vkey(vkey_of(key), true);
+    }
+}
@@ -0,0 +1,126 @@
+//! Platform-independent key identifiers. The DSL, config and layer-trigger
+//! code all speak in terms of [`Key`]; each [`crate::injector::KeyInjector`]
+//! backend is responsible for mapping a `Key` to whatever its platform
+//! needs (a Windows `VIRTUAL_KEY`, a Linux `KEY_*` code, ...).
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Ctrl,
+    Shift,
+    Alt,
+    Win,
+    Tab,
+    Enter,
+    Escape,
+    Space,
+    Backspace,
+    Delete,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+}
+
+impl Key {
+    pub fn is_modifier(self) -> bool {
+        matches!(self, Key::Ctrl | Key::Shift | Key::Alt | Key::Win)
+    }
+}
+
+/// Looks up a key name (case-insensitive) used inside a DSL `<...>` token
+/// or a config binding key, e.g. `"Ctrl"`, `"Tab"`, `"F5"`, `"l"`.
+pub fn by_name(name: &str) -> Option<Key> {
+    let key = match name.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Key::Ctrl,
+        "shift" => Key::Shift,
+        "alt" => Key::Alt,
+        "win" | "meta" | "super" => Key::Win,
+        "tab" => Key::Tab,
+        "enter" | "return" => Key::Enter,
+        "esc" | "escape" => Key::Escape,
+        "space" => Key::Space,
+        "backspace" => Key::Backspace,
+        "delete" | "del" => Key::Delete,
+        "up" => Key::Up,
+        "down" => Key::Down,
+        "left" => Key::Left,
+        "right" => Key::Right,
+        "home" => Key::Home,
+        "end" => Key::End,
+        "pageup" => Key::PageUp,
+        "pagedown" => Key::PageDown,
+        "f1" => Key::F1,
+        "f2" => Key::F2,
+        "f3" => Key::F3,
+        "f4" => Key::F4,
+        "f5" => Key::F5,
+        "f6" => Key::F6,
+        "f7" => Key::F7,
+        "f8" => Key::F8,
+        "f9" => Key::F9,
+        "f10" => Key::F10,
+        "f11" => Key::F11,
+        "f12" => Key::F12,
+        other => {
+            let mut chars = other.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) if c.is_ascii_alphanumeric() => {
+                    Key::Char(c.to_ascii_lowercase())
+                }
+                _ => return None,
+            }
+        }
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn by_name_is_case_insensitive_for_named_keys() {
+        assert_eq!(by_name("tab"), Some(Key::Tab));
+        assert_eq!(by_name("TAB"), Some(Key::Tab));
+        assert_eq!(by_name("Esc"), Some(Key::Escape));
+        assert_eq!(by_name("F5"), Some(Key::F5));
+    }
+
+    #[test]
+    fn by_name_recognizes_aliases() {
+        assert_eq!(by_name("control"), Some(Key::Ctrl));
+        assert_eq!(by_name("super"), Some(Key::Win));
+        assert_eq!(by_name("return"), Some(Key::Enter));
+        assert_eq!(by_name("del"), Some(Key::Delete));
+    }
+
+    #[test]
+    fn by_name_parses_single_alphanumeric_char_lowercased() {
+        assert_eq!(by_name("L"), Some(Key::Char('l')));
+        assert_eq!(by_name("7"), Some(Key::Char('7')));
+    }
+
+    #[test]
+    fn by_name_rejects_unknown_or_multi_char_input() {
+        assert_eq!(by_name("notakey"), None);
+        assert_eq!(by_name(""), None);
+        assert_eq!(by_name("!"), None);
+    }
+}
@@ -1,118 +1,122 @@
+mod cli;
+mod config;
+mod dsl;
+#[cfg(target_os = "windows")]
+mod hook;
+mod injector;
+mod key;
+
+use cli::Mode;
+use dsl::Action;
+use injector::{DryRunInjector, KeyInjector};
+use key::Key;
 use std::env;
-use windows::{
-    Win32::UI::Input::KeyboardAndMouse::*,
-};
+use std::io::Read;
 
-fn send_key_input(vkey: u8, flags: KEYBD_EVENT_FLAGS) {
-    unsafe {
-        keybd_event(vkey, 0, flags, 0);
-    }
-}
+/// Drives an injector from a resolved action list: literal text is typed
+/// as-is, and a chord presses all modifiers, presses/releases the base
+/// key, then releases the modifiers in reverse order (so the last-pressed
+/// modifier is the first released, matching how a human holding a chord
+/// would let go).
+pub(crate) fn send_actions(injector: &mut dyn KeyInjector, actions: &[Action]) {
+    for action in actions {
+        match action {
+            Action::Text(text) => {
+                let lines: Vec<&str> = text.split('\n').collect();
+                for (i, line) in lines.iter().enumerate() {
+                    if !line.is_empty() {
+                        injector.type_text(line);
+                    }
 
-fn send_text(text: &str) {
-    // Small delay to ensure target window is ready
-    std::thread::sleep(std::time::Duration::from_millis(50));
-    
-    unsafe {
-        for ch in text.chars() {
-            let (vkey, shift_needed) = get_virtual_key(ch);
-            
-            // Press Shift if needed
-            if shift_needed {
-                send_key_input(VK_SHIFT.0 as u8, KEYBD_EVENT_FLAGS(0));
+                    if i < lines.len() - 1 {
+                        injector.key_down(Key::Enter);
+                        injector.key_up(Key::Enter);
+                        std::thread::sleep(std::time::Duration::from_millis(10));
+                    }
+                }
             }
-            
-            // Press the key
-            send_key_input(vkey, KEYBD_EVENT_FLAGS(0));
-            
-            // Release the key
-            send_key_input(vkey, KEYBD_EVENT_FLAGS(KEYEVENTF_KEYUP));
-            
-            // Release Shift if it was pressed
-            if shift_needed {
-                send_key_input(VK_SHIFT.0 as u8, KEYBD_EVENT_FLAGS(KEYEVENTF_KEYUP));
+            Action::Chord { mods, key } => {
+                for &m in mods {
+                    injector.key_down(m);
+                }
+                injector.key_down(*key);
+                injector.key_up(*key);
+                for &m in mods.iter().rev() {
+                    injector.key_up(m);
+                }
             }
-            
-            // Minimal delay between characters (1ms for speed)
-            std::thread::sleep(std::time::Duration::from_millis(1));
         }
     }
 }
 
-fn get_virtual_key(ch: char) -> (u8, bool) {
-    match ch {
-        'a'..='z' => ((ch as u8 - b'a' + b'A') as u8, false),
-        'A'..='Z' => (ch as u8, true),
-        '0'..='9' => (ch as u8, false),
-        ' ' => (VK_SPACE.0 as u8, false),
-        '\n' => (VK_RETURN.0 as u8, false),
-        '\t' => (VK_TAB.0 as u8, false),
-        _ => {
-            // For special characters, use VkKeyScan
-            unsafe {
-                let scan = VkKeyScanW(ch as u16);
-                let vkey = (scan.0 & 0xFF) as u8;
-                let shift = (scan.0 & 0x0100) != 0;
-                
-                if vkey != 0 {
-                    (vkey, shift)
-                } else {
-                    // Fallback: map common characters
-                    match ch {
-                        '.' => (VK_OEM_PERIOD.0 as u8, false),
-                        ',' => (VK_OEM_COMMA.0 as u8, false),
-                        '!' => (VK_1.0 as u8, true),
-                        '@' => (VK_2.0 as u8, true),
-                        '#' => (VK_3.0 as u8, true),
-                        '$' => (VK_4.0 as u8, true),
-                        '%' => (VK_5.0 as u8, true),
-                        '^' => (VK_6.0 as u8, true),
-                        '&' => (VK_7.0 as u8, true),
-                        '*' => (VK_8.0 as u8, true),
-                        '(' => (VK_9.0 as u8, true),
-                        ')' => (VK_0.0 as u8, true),
-                        '-' => (VK_OEM_MINUS.0 as u8, false),
-                        '=' => (VK_OEM_PLUS.0 as u8, false),
-                        '[' => (VK_OEM_4.0 as u8, false),
-                        ']' => (VK_OEM_6.0 as u8, false),
-                        '\\' => (VK_OEM_5.0 as u8, false),
-                        ';' => (VK_OEM_1.0 as u8, false),
-                        '\'' => (VK_OEM_7.0 as u8, false),
-                        '/' => (VK_OEM_2.0 as u8, false),
-                        '`' => (VK_OEM_3.0 as u8, false),
-                        _ => (ch as u8, false),
-                    }
-                }
-            }
+fn read_stdin_to_string() -> String {
+    let mut input = String::new();
+    std::io::stdin()
+        .read_to_string(&mut input)
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to read stdin: {e}");
+            std::process::exit(1);
+        });
+    input
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(unused_variables))]
+fn run_hook(path: String, layer: Option<String>) {
+    #[cfg(target_os = "windows")]
+    {
+        let cfg = config::Config::load(&path).unwrap_or_else(|e| {
+            eprintln!("Failed to load config: {e}");
+            std::process::exit(1);
+        });
+        if let Err(e) = hook::run(cfg, layer.as_deref()) {
+            eprintln!("Failed to install keyboard hook: {e}");
+            std::process::exit(1);
         }
     }
+    #[cfg(not(target_os = "windows"))]
+    {
+        eprintln!("--hook is only supported on Windows");
+        std::process::exit(1);
+    }
 }
 
 fn main() {
     let args: Vec<String> = env::args().collect();
-    
-    if args.len() < 2 {
-        eprintln!("Usage: keyboard-inject <text>");
+    let opts = cli::parse(&args).unwrap_or_else(|e| {
+        eprintln!("{e}");
         std::process::exit(1);
-    }
-    
-    let text = &args[1];
-    
-    // Split by newlines and send each line
-    let lines: Vec<&str> = text.split('\n').collect();
-    
-    for (i, line) in lines.iter().enumerate() {
-        if !line.is_empty() {
-            send_text(line);
+    });
+
+    let text = match opts.mode {
+        Mode::Hook { config, layer } => {
+            run_hook(config, layer);
+            return;
         }
-        
-        // Press Enter after each line except the last
-        if i < lines.len() - 1 {
-            unsafe {
-                send_key_input(VK_RETURN.0 as u8, KEYBD_EVENT_FLAGS(0));
-                send_key_input(VK_RETURN.0 as u8, KEYBD_EVENT_FLAGS(KEYEVENTF_KEYUP));
+        Mode::ShowConfig(path) => {
+            match config::Config::load(&path) {
+                Ok(cfg) => {
+                    println!("Loaded {} layer(s):", cfg.layers.len());
+                    for layer in &cfg.layers {
+                        println!("  {} ({} binding(s))", layer.name, layer.bindings.len());
+                    }
+                }
+                Err(e) => {
+                    eprintln!("Failed to load config: {e}");
+                    std::process::exit(1);
+                }
             }
-            std::thread::sleep(std::time::Duration::from_millis(10));
+            return;
         }
-    }
+        Mode::Stdin => read_stdin_to_string(),
+        Mode::Text(text) => text,
+    };
+
+    let actions = dsl::parse(&text);
+
+    let mut injector: Box<dyn KeyInjector> = if opts.dry_run {
+        Box::new(DryRunInjector)
+    } else {
+        injector::make_injector(opts.injector)
+    };
+    send_actions(&mut *injector, &actions);
 }